@@ -10,24 +10,38 @@ mod app {
     const SCREEN_WIDTH: i32 = 240;
     const SCREEN_HEIGHT: i32 = 240;
     const CENTER: i32 = 120;
+    const DIAL_RADIUS: i32 = 110;
+    const LONG_PRESS_MS: u64 = 2000;
+    const BUZZER_HZ: u32 = 2000;
 
     // Import required libraries and traits
+    use core::f32::consts::PI;
     use core::fmt::Write;
+    use cst816s::{TouchGesture, CST816S};
     use display_interface_spi::SPIInterface;
     use embedded_graphics::{
         mono_font::{ascii::FONT_9X18, MonoTextStyle},
         pixelcolor::Rgb565,
         prelude::*,
-        primitives::{PrimitiveStyle, Rectangle},
+        primitives::{Circle, Line, PrimitiveStyle, Rectangle},
         text::Text,
     };
+    use embedded_hal::digital::v2::InputPin;
+    use fugit::TimerInstantU64;
     use heapless::String;
+    use libm::{cosf, sinf};
     use nrf52840_hal::{
         clocks::Clocks,
-        gpio::{p0, p0::P0_12, p0::P0_13, p1, p1::P1_03, p1::P1_05, Level, Output, PushPull},
+        gpio::{
+            p0, p0::P0_06, p0::P0_09, p0::P0_10, p0::P0_12, p0::P0_13, p1, p1::P1_02, p1::P1_03,
+            p1::P1_05, p1::P1_10, Floating, Input, Level, Output, PullUp, PushPull,
+        },
         gpiote::Gpiote,
-        pac::SPIM0,
+        pac::{PWM0, SPIM0, TWIM1},
+        pwm::{self, Pwm},
         spim,
+        time::Hertz,
+        twim,
         Delay,
     };
     use panic_halt as _;
@@ -36,36 +50,391 @@ mod app {
 
     // Define the monotonic timer based on SysTick
     #[monotonic(binds = SysTick, default = true)]
-    type Mono = Systick<100>; 
+    type Mono = Systick<100>;
+
+    // Instant type produced by the `Mono` monotonic, used to time button presses
+    type Instant = TimerInstantU64<100>;
+
+    // Concrete display type every `App` renders to
+    type Display = ST7789<
+        SPIInterface<spim::Spim<SPIM0>, P0_13<Output<PushPull>>, P0_12<Output<PushPull>>>,
+        P1_03<Output<PushPull>>,
+        P1_05<Output<PushPull>>,
+    >;
+
+    // Abstracted input, so an `App` doesn't need to know whether it came from
+    // a physical button or a touch gesture.
+    #[derive(Clone, Copy, PartialEq)]
+    enum InputEvent {
+        Primary,
+        Secondary,
+        Reset,
+        ToggleView,
+    }
+
+    // A single screen the launcher can host. Adding a new screen means
+    // implementing this trait and wiring it into `AppId`, `Shared`,
+    // `MENU_ITEMS`/`MenuApp::selected_app` and the per-app `match` arms in
+    // `update_display` and `dispatch_input`.
+    trait App {
+        fn render(&mut self, display: &mut Display, elapsed_ms: u64);
+        fn on_input(&mut self, event: InputEvent);
+
+        // Called once when the launcher switches to this app, before the
+        // first `render`. The default just blanks the screen; apps that
+        // track dirty-rect state between frames also reset it here so they
+        // don't diff against whatever the previous app last drew.
+        fn on_enter(&mut self, display: &mut Display) {
+            display.clear(Rgb565::BLACK).unwrap();
+        }
+    }
+
+    // Which app is currently on screen
+    #[derive(Clone, Copy, PartialEq)]
+    enum AppId {
+        Menu,
+        Countdown,
+        Stopwatch,
+    }
+
+    const MENU_ITEMS: [&str; 2] = ["Countdown Timer", "Stopwatch"];
+
+    // Lists the installed apps; short press enters the highlighted one
+    struct MenuApp {
+        selected: usize,
+    }
+
+    impl MenuApp {
+        fn selected_app(&self) -> AppId {
+            match self.selected {
+                0 => AppId::Countdown,
+                _ => AppId::Stopwatch,
+            }
+        }
+    }
+
+    impl App for MenuApp {
+        fn render(&mut self, display: &mut Display, elapsed_ms: u64) {
+            display.clear(Rgb565::BLACK).unwrap();
+
+            Text::new(
+                "-- Select an app --",
+                Point::new(20, 30),
+                MonoTextStyle::new(&FONT_9X18, Rgb565::WHITE),
+            )
+            .draw(display)
+            .ok();
+
+            // Blink the highlighted entry so the launcher still feels alive
+            // even though nothing else on this screen is animating.
+            let blink_on = (elapsed_ms / 500) % 2 == 0;
+            for (i, label) in MENU_ITEMS.iter().enumerate() {
+                let color = if i == self.selected && blink_on {
+                    Rgb565::YELLOW
+                } else {
+                    Rgb565::WHITE
+                };
+                Text::new(
+                    label,
+                    Point::new(20, 80 + i as i32 * 30),
+                    MonoTextStyle::new(&FONT_9X18, color),
+                )
+                .draw(display)
+                .ok();
+            }
+
+            update_display::spawn_after(500.millis()).ok();
+        }
+
+        fn on_input(&mut self, event: InputEvent) {
+            if event == InputEvent::Secondary {
+                self.selected = (self.selected + 1) % MENU_ITEMS.len();
+            }
+        }
+    }
+
+    // The original countdown timer, now hosted as one app among several
+    struct CountdownApp {
+        running: bool,
+        time_left: i32,
+        analog_mode: bool,
+        alarm_started: bool,
+        // Previous frame's bar view, so ticks only repaint what changed
+        last_progress_height: i32,
+        last_color: Rgb565,
+        last_text: String<45>,
+        last_text_pos: Point,
+    }
+
+    impl CountdownApp {
+        fn new() -> Self {
+            CountdownApp {
+                running: false,
+                time_left: MAX_TIME,
+                analog_mode: false,
+                alarm_started: false,
+                last_progress_height: -1,
+                last_color: Rgb565::BLACK,
+                last_text: String::new(),
+                last_text_pos: Point::zero(),
+            }
+        }
+    }
+
+    impl App for CountdownApp {
+        fn on_enter(&mut self, display: &mut Display) {
+            display.clear(Rgb565::BLACK).unwrap();
+            self.last_progress_height = -1;
+            self.last_text.clear();
+        }
+
+        fn render(&mut self, display: &mut Display, _elapsed_ms: u64) {
+            let color = if self.running && self.time_left <= 1 {
+                Rgb565::RED
+            } else if self.running {
+                Rgb565::YELLOW
+            } else {
+                Rgb565::GREEN
+            };
+
+            // The plain bar is the hot path (ticks once a second), so it gets
+            // a dirty-rectangle update instead of a full-screen clear. The
+            // analog dial redraws everything and invalidates the bar's
+            // tracked state so it doesn't diff against a stale frame.
+            let text_style = MonoTextStyle::new(&FONT_9X18, Rgb565::WHITE);
+
+            // Where the bar's fill currently starts, so a text label sitting
+            // partway down the screen can clear its old box against black
+            // above that line and the bar's own color below it, instead of
+            // assuming the whole label sits on one or the other.
+            let progress_height =
+                (self.time_left as f32 / MAX_TIME as f32 * SCREEN_HEIGHT as f32) as i32;
+            let bar_top = SCREEN_HEIGHT - progress_height;
+
+            if self.analog_mode {
+                display.clear(Rgb565::BLACK).unwrap();
+                self.last_progress_height = -1;
+                self.last_text.clear();
+                draw_clock_face(display, self.time_left, color);
+            } else {
+                update_progress_bar(
+                    display,
+                    &mut self.last_progress_height,
+                    progress_height,
+                    &mut self.last_color,
+                    color,
+                );
+            }
+
+            let mut text: String<8> = String::new();
+            if !self.running {
+                self.alarm_started = false;
+
+                let mut instructions: String<45> = String::new();
+                write!(
+                    instructions,
+                    "<-- Start Timer\nSet Time-->\nTime: {:02}s",
+                    self.time_left
+                )
+                .unwrap();
+                update_text(
+                    display,
+                    &mut self.last_text,
+                    &mut self.last_text_pos,
+                    &instructions,
+                    Point::new(CENTER + 10, CENTER - 30),
+                    text_style,
+                    bar_top,
+                    color,
+                );
+            } else {
+                if self.time_left <= 1 {
+                    write!(text, "BEEEP").unwrap();
+                    if !self.alarm_started {
+                        self.alarm_started = true;
+                        beep::spawn().ok();
+                    }
+                } else {
+                    write!(text, "{:02}", self.time_left).unwrap();
+                }
+                update_text(
+                    display,
+                    &mut self.last_text,
+                    &mut self.last_text_pos,
+                    &text,
+                    Point::new(CENTER + 50, CENTER),
+                    text_style,
+                    bar_top,
+                    color,
+                );
+
+                if self.time_left <= 0 {
+                    // Expiry ends this ringing episode here, not on the next
+                    // render: reset the latch now so a restart from zero
+                    // spawns `beep` again instead of finding it still set.
+                    // That restart expires on this same render, so the
+                    // spawned `beep` sees `running` false and stays silent.
+                    self.running = false;
+                    self.alarm_started = false;
+                } else {
+                    self.time_left -= 1;
+                    update_display::spawn_after(1.secs()).ok();
+                }
+            }
+        }
+
+        fn on_input(&mut self, event: InputEvent) {
+            match event {
+                InputEvent::Primary => self.running = !self.running,
+                InputEvent::Secondary => {
+                    self.running = false;
+                    self.time_left = (self.time_left + 5) % MAX_TIME;
+                }
+                InputEvent::Reset => {
+                    self.running = false;
+                    self.time_left = MAX_TIME;
+                }
+                InputEvent::ToggleView => self.analog_mode = !self.analog_mode,
+            }
+        }
+    }
+
+    // A lap-free stopwatch counting up from zero
+    struct StopwatchApp {
+        running: bool,
+        time_left: i32,
+        last_text: String<45>,
+        last_text_pos: Point,
+    }
+
+    impl StopwatchApp {
+        fn new() -> Self {
+            StopwatchApp {
+                running: false,
+                time_left: 0,
+                last_text: String::new(),
+                last_text_pos: Point::zero(),
+            }
+        }
+    }
+
+    impl App for StopwatchApp {
+        fn render(&mut self, display: &mut Display, _elapsed_ms: u64) {
+            display.clear(Rgb565::BLACK).unwrap();
+            self.last_text.clear();
+
+            let color = if self.running {
+                Rgb565::YELLOW
+            } else {
+                Rgb565::GREEN
+            };
+            Rectangle::new(Point::new(0, 0), Size::new(SCREEN_WIDTH as u32, 8))
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(display)
+                .unwrap();
+
+            let mut text: String<45> = String::new();
+            write!(
+                text,
+                "{:02}:{:02}\n<-- Start/Pause\nHold <-- Reset",
+                self.time_left / 60,
+                self.time_left % 60
+            )
+            .unwrap();
+            Text::new(
+                &text,
+                Point::new(CENTER - 30, CENTER),
+                MonoTextStyle::new(&FONT_9X18, Rgb565::WHITE),
+            )
+            .draw(display)
+            .ok();
+            self.last_text_pos = Point::new(CENTER - 30, CENTER);
+            let _ = self.last_text.push_str(&text);
+
+            if self.running {
+                self.time_left += 1;
+                update_display::spawn_after(1.secs()).ok();
+            }
+        }
+
+        fn on_input(&mut self, event: InputEvent) {
+            match event {
+                InputEvent::Primary => self.running = !self.running,
+                InputEvent::Reset => {
+                    self.running = false;
+                    self.time_left = 0;
+                }
+                InputEvent::Secondary | InputEvent::ToggleView => {}
+            }
+        }
+    }
 
     // Define shared state variables
     #[shared]
     struct Shared {
-        running: bool,
-        time_left: i32,
+        active_app: AppId,
+        menu_app: MenuApp,
+        countdown_app: CountdownApp,
+        stopwatch_app: StopwatchApp,
+        muted: bool,
     }
 
     // Define local resources
     #[local]
     struct Local {
-        display: ST7789<SPIInterface<spim::Spim<SPIM0>, P0_13<Output<PushPull>>, P0_12<Output<PushPull>>>, P1_03<Output<PushPull>>, P1_05<Output<PushPull>>>,
+        display: Display,
         gpiote: Gpiote,
+        touch: CST816S<twim::Twim<TWIM1>, P0_09<Input<Floating>>, P0_10<Output<PushPull>>>,
+        button_a: P1_02<Input<PullUp>>,
+        button_b: P1_10<Input<PullUp>>,
+        a_press_start: Option<Instant>,
+        b_press_start: Option<Instant>,
+        combo_press_start: Option<Instant>,
+        buzzer: Pwm<PWM0>,
+        buzzer_on: bool,
+        last_active_app: Option<AppId>,
     }
 
     // Initialization function to set up hardware and initialize state
     #[init]
-    fn init(ctx: initialize::Context) -> (Shared, Local, init::Monotonics) {
+    fn init(ctx: init::Context) -> (Shared, Local, init::Monotonics) {
         // Initialize GPIO ports and pins
         let port0 = p0::Parts::new(ctx.device.P0);
         let port1 = p1::Parts::new(ctx.device.P1);
-    
-        // Initialize buttons and configure interrupts
-        let button_a = port1.p1_02.into_pullup_input().degrade();
-        let button_b = port1.p1_10.into_pullup_input().degrade();
+
+        // Initialize buttons and configure interrupts. Both edges are watched
+        // (via `toggle()`) so a press and its release can each be timestamped,
+        // which is how `handle_buttons` tells a short press from a long press.
+        let button_a = port1.p1_02.into_pullup_input();
+        let button_b = port1.p1_10.into_pullup_input();
         let gpiote = Gpiote::new(ctx.device.GPIOTE);
-        gpiote.channel0().input_pin(&button_a).hi_to_lo().enable_interrupt();
-        gpiote.channel1().input_pin(&button_b).hi_to_lo().enable_interrupt();
-    
+        gpiote.channel0().input_pin(&button_a).toggle().enable_interrupt();
+        gpiote.channel1().input_pin(&button_b).toggle().enable_interrupt();
+
+        // Initialize CST816S capacitive touch controller (I2C + data-ready line)
+        let touch_sda = port0.p0_08.into_floating_input().degrade();
+        let touch_scl = port0.p0_07.into_floating_input().degrade();
+        let touch_int = port0.p0_09.into_floating_input();
+        let touch_rst = port0.p0_10.into_push_pull_output(Level::High);
+        gpiote.channel2().input_pin(&touch_int).hi_to_lo().enable_interrupt();
+        let touch_i2c = twim::Twim::new(
+            ctx.device.TWIM1,
+            twim::Pins {
+                sda: touch_sda,
+                scl: touch_scl,
+            },
+            twim::Frequency::K400,
+        );
+        let mut touch = CST816S::new(touch_i2c, touch_int, touch_rst);
+
+        // Initialize the PWM-driven buzzer on a spare GPIO
+        let buzzer_pin = port0.p0_06.into_push_pull_output(Level::Low).degrade();
+        let buzzer = Pwm::new(ctx.device.PWM0);
+        buzzer.set_output_pin(pwm::Channel::C0, buzzer_pin);
+        buzzer.set_period(Hertz(BUZZER_HZ));
+        buzzer.set_duty_on_common(buzzer.max_duty() / 2);
+        buzzer.disable();
+
         // Initialize SPI pins for display
         let cs_pin = port0.p0_12.into_push_pull_output(Level::High);
         let dc_pin = port0.p0_13.into_push_pull_output(Level::Low);
@@ -73,7 +442,7 @@ mod app {
         let mosi_pin = port0.p0_15.into_push_pull_output(Level::Low).degrade();
         let rst_pin = port1.p1_03.into_push_pull_output(Level::Low);
         let backlight_pin = port1.p1_05.into_push_pull_output(Level::Low);
-    
+
         // Initialize SPI interface and display
         let spi = spim::Spim::new(
             ctx.device.SPIM0,
@@ -86,27 +455,48 @@ mod app {
             spim::MODE_3,
             122,
         );
-    
+
         let spi_display = SPIInterface::new(spi, dc_pin, cs_pin);
         let mut display = ST7789::new(spi_display, Some(rst_pin), Some(backlight_pin), 240, 240);
-        
+
         // Initialize display with appropriate settings
         let mut delay = Delay::new(ctx.core.SYST);
         display.init(&mut delay).unwrap();
         display.set_orientation(Orientation::LandscapeSwapped).unwrap();
         display.clear(Rgb565::BLACK).unwrap();
-        
+
+        // Bring up the touch controller now that we have a delay source
+        touch.setup(&mut delay).ok();
+
         // Release SYST timer for monotonic use
         let syst = delay.free();
         let mono = Systick::new(syst, 64_000_000);
-    
+
         // Enable external high-frequency oscillator
         Clocks::new(ctx.device.CLOCK).enable_ext_hfosc();
-    
+
         // Return initialized shared state, local resources, and monotonic timer
         (
-            Shared { running: false, time_left: MAX_TIME },
-            Local { display, gpiote },
+            Shared {
+                active_app: AppId::Menu,
+                menu_app: MenuApp { selected: 0 },
+                countdown_app: CountdownApp::new(),
+                stopwatch_app: StopwatchApp::new(),
+                muted: false,
+            },
+            Local {
+                display,
+                gpiote,
+                touch,
+                button_a,
+                button_b,
+                a_press_start: None,
+                b_press_start: None,
+                combo_press_start: None,
+                buzzer,
+                buzzer_on: false,
+                last_active_app: None,
+            },
             init::Monotonics(mono),
         )
     }
@@ -119,85 +509,349 @@ mod app {
         }
     }
 
-    // Task to update the display based on shared and local data
-    #[task(shared = [running, time_left], local = [display])]
+    // Renders whichever app is currently active. Apps reschedule their own
+    // next frame (e.g. once a second for a ticking timer), so this task has
+    // no knowledge of any one app's refresh rate.
+    #[task(capacity = 2, shared = [active_app, menu_app, countdown_app, stopwatch_app], local = [display, last_active_app])]
     fn update_display(mut ctx: update_display::Context) {
-        let running = ctx.shared.running.lock(|r| *r);
-        let time_left = ctx.shared.time_left.lock(|t| *t);
-
-        ctx.local.display.clear(Rgb565::BLACK).unwrap();
+        let elapsed_ms = monotonics::now().duration_since_epoch().to_millis();
+        let active = ctx.shared.active_app.lock(|a| *a);
+        // The active app changed since we last rendered (menu selection, a
+        // long press, a swipe, ...): invalidate so the incoming app doesn't
+        // diff its dirty-rect state against the outgoing app's frame.
+        let entering = *ctx.local.last_active_app != Some(active);
+        *ctx.local.last_active_app = Some(active);
+        match active {
+            AppId::Menu => ctx.shared.menu_app.lock(|app| {
+                if entering {
+                    app.on_enter(ctx.local.display);
+                }
+                app.render(ctx.local.display, elapsed_ms)
+            }),
+            AppId::Countdown => ctx.shared.countdown_app.lock(|app| {
+                if entering {
+                    app.on_enter(ctx.local.display);
+                }
+                app.render(ctx.local.display, elapsed_ms)
+            }),
+            AppId::Stopwatch => ctx.shared.stopwatch_app.lock(|app| {
+                if entering {
+                    app.on_enter(ctx.local.display);
+                }
+                app.render(ctx.local.display, elapsed_ms)
+            }),
+        }
+    }
 
-        let color = if running && time_left <= 1 {
-            Rgb565::RED
-        } else if running {
-            Rgb565::YELLOW
-        } else {
-            Rgb565::GREEN
-        };
+    // Toggles the buzzer on and off to produce a 2kHz beep/silence burst
+    // sequence at expiry. Stops as soon as the countdown app is no longer
+    // running or the alarm is muted.
+    #[task(shared = [countdown_app, muted], local = [buzzer, buzzer_on])]
+    fn beep(mut ctx: beep::Context) {
+        let running = ctx.shared.countdown_app.lock(|app| app.running);
+        let muted = ctx.shared.muted.lock(|m| *m);
 
-        let progress_height = (time_left as f32 / MAX_TIME as f32 * SCREEN_HEIGHT as f32) as i32;
+        if !running || muted {
+            ctx.local.buzzer.disable();
+            *ctx.local.buzzer_on = false;
+            return;
+        }
 
-        // Draw progress bar
-        Rectangle::new(
-            Point::new(0, SCREEN_HEIGHT - progress_height),
-            Size::new(SCREEN_WIDTH as u32, progress_height as u32),
-        )
-        .into_styled(PrimitiveStyle::with_fill(color))
-        .draw(ctx.local.display)
-        .unwrap();
-
-        // Prepare and display text based on current state
-        let mut text: String<8> = String::new();
-        if !running {
-            let mut instructions: String<45> = String::new();
-            write!(instructions, "<-- Start Timer\nSet Time-->\nTime: {:02}s", time_left).unwrap();
-            Text::new(
-                &instructions,
-                Point::new(CENTER + 10, CENTER - 30),
-                MonoTextStyle::new(&FONT_9X18, Rgb565::WHITE),
-            )
-            .draw(ctx.local.display)
-            .unwrap();
+        if *ctx.local.buzzer_on {
+            ctx.local.buzzer.disable();
         } else {
-            if time_left <= 1 {
-                write!(text, "BEEEP").unwrap();
-            } else {
-                write!(text, "{:02}", time_left).unwrap();
-            }
-            Text::new(
-                &text,
-                Point::new(CENTER + 50, CENTER),
-                MonoTextStyle::new(&FONT_9X18, Rgb565::WHITE),
-            )
-            .draw(ctx.local.display)
-            .unwrap();
-
-            // Update timer and schedule next display update
-            if time_left <= 0 {
-                ctx.shared.running.lock(|r| *r = false);
-            } else {
-                ctx.shared.time_left.lock(|t| *t -= 1);
-                update_display::spawn_after(1.secs()).unwrap();
-            }
+            ctx.local.buzzer.enable();
         }
+        *ctx.local.buzzer_on = !*ctx.local.buzzer_on;
+
+        beep::spawn_after(250.millis()).unwrap();
     }
 
-    // Interrupt handler for button presses
-    #[task(binds = GPIOTE, local = [gpiote], shared = [running, time_left])]
+    // Interrupt handler for button presses and touch events. A's long press
+    // resets the active app, B's long press returns to the menu, and both
+    // held together briefly mutes or, held longer, toggles the analog view.
+    // A short press is forwarded to whichever app is active, or used to
+    // select a menu entry.
+    #[task(
+        binds = GPIOTE,
+        local = [gpiote, touch, button_a, button_b, a_press_start, b_press_start, combo_press_start],
+        shared = [active_app, menu_app, countdown_app, stopwatch_app, muted]
+    )]
     fn handle_buttons(mut ctx: handle_buttons::Context) {
-        // Handle button A press
+        // Handle button A
         if ctx.local.gpiote.channel0().is_event_triggered() {
             ctx.local.gpiote.channel0().reset_events();
-            ctx.shared.running.lock(|r| *r = true);
-            update_display::spawn().unwrap();
+            if ctx.local.button_a.is_low().unwrap() {
+                if ctx.local.button_b.is_low().unwrap() {
+                    // Both buttons just became held together: start timing
+                    // the combo instead of tracking A's own press. Whichever
+                    // button was already down recorded a press timestamp
+                    // before this combo was recognized; drop it so its
+                    // eventual release isn't misread as its own press.
+                    *ctx.local.combo_press_start = Some(monotonics::now());
+                    *ctx.local.b_press_start = None;
+                } else {
+                    // hi_to_lo: button pressed down, remember when
+                    *ctx.local.a_press_start = Some(monotonics::now());
+                }
+            } else if let Some(combo_at) = ctx.local.combo_press_start.take() {
+                // The combo ends as soon as either button releases first
+                silence_alarm(&mut ctx);
+                handle_combo_release(&mut ctx, combo_at);
+            } else if let Some(pressed_at) = ctx.local.a_press_start.take() {
+                // lo_to_hi: button released, classify the press. A's long
+                // press resets the active app (button B's returns to the
+                // menu instead) so a button-only board can still clear the
+                // countdown back to MAX_TIME or the stopwatch back to zero.
+                silence_alarm(&mut ctx);
+                let held_ms = (monotonics::now() - pressed_at).to_millis();
+                if held_ms >= LONG_PRESS_MS {
+                    dispatch_input(&mut ctx, InputEvent::Reset);
+                } else {
+                    dispatch_input(&mut ctx, InputEvent::Primary);
+                }
+                // Queued redraws (e.g. a still-pending countdown tick) may
+                // already fill the task's two slots; missing this one is
+                // fine since the next tick repaints the new state anyway.
+                update_display::spawn().ok();
+            }
         }
-        // Handle button B press
+        // Handle button B
         else if ctx.local.gpiote.channel1().is_event_triggered() {
             ctx.local.gpiote.channel1().reset_events();
-            ctx.shared.running.lock(|r| *r = false);
-            ctx.shared.time_left.lock(|t| *t = (*t + 5) % (MAX_TIME));
-            update_display::spawn().unwrap();
+            if ctx.local.button_b.is_low().unwrap() {
+                if ctx.local.button_a.is_low().unwrap() {
+                    // Both buttons just became held together: start timing
+                    // the combo instead of tracking B's own press.
+                    *ctx.local.combo_press_start = Some(monotonics::now());
+                    *ctx.local.a_press_start = None;
+                } else {
+                    *ctx.local.b_press_start = Some(monotonics::now());
+                }
+            } else if let Some(combo_at) = ctx.local.combo_press_start.take() {
+                // The combo ends as soon as either button releases first
+                silence_alarm(&mut ctx);
+                handle_combo_release(&mut ctx, combo_at);
+            } else if let Some(pressed_at) = ctx.local.b_press_start.take() {
+                silence_alarm(&mut ctx);
+                let held_ms = (monotonics::now() - pressed_at).to_millis();
+                if held_ms >= LONG_PRESS_MS {
+                    ctx.shared.active_app.lock(|a| *a = AppId::Menu);
+                } else {
+                    dispatch_input(&mut ctx, InputEvent::Secondary);
+                }
+                update_display::spawn().ok();
+            }
+        }
+        // Handle a touch controller data-ready event
+        else if ctx.local.gpiote.channel2().is_event_triggered() {
+            ctx.local.gpiote.channel2().reset_events();
+            if let Some(event) = ctx.local.touch.read_one_touch_event(true) {
+                silence_alarm(&mut ctx);
+                if event.gesture == TouchGesture::SwipeDown {
+                    dispatch_input(&mut ctx, InputEvent::Reset);
+                } else if event.gesture == TouchGesture::SwipeUp {
+                    dispatch_input(&mut ctx, InputEvent::ToggleView);
+                } else if event.x < SCREEN_WIDTH / 2 {
+                    dispatch_input(&mut ctx, InputEvent::Primary);
+                } else {
+                    dispatch_input(&mut ctx, InputEvent::Secondary);
+                }
+                update_display::spawn().ok();
+            }
+        }
+    }
+
+    // Both buttons held together briefly mutes/unmutes the alarm, same as
+    // before; held past `LONG_PRESS_MS` it instead toggles the analog/bar
+    // view, giving a button-only board the same swipe-up affordance touch
+    // boards get via `InputEvent::ToggleView`.
+    fn handle_combo_release(ctx: &mut handle_buttons::Context, combo_at: Instant) {
+        let held_ms = (monotonics::now() - combo_at).to_millis();
+        if held_ms >= LONG_PRESS_MS {
+            dispatch_input(ctx, InputEvent::ToggleView);
+        } else {
+            ctx.shared.muted.lock(|m| *m = !*m);
+        }
+        update_display::spawn().ok();
+    }
+
+    // A ringing alarm is stopped by any button press or touch, not just the
+    // mute combo, regardless of which app is currently active (a long press
+    // back to the menu, for instance, shouldn't leave the buzzer running
+    // behind it).
+    fn silence_alarm(ctx: &mut handle_buttons::Context) {
+        ctx.shared.countdown_app.lock(|app| {
+            if app.alarm_started {
+                app.running = false;
+            }
+        });
+    }
+
+    // Routes an abstracted input event to the currently active app. While the
+    // menu is active, `Primary` enters the highlighted app instead of being
+    // forwarded, since `App::on_input` has no way to change `active_app`.
+    fn dispatch_input(ctx: &mut handle_buttons::Context, event: InputEvent) {
+        ctx.shared.active_app.lock(|active| match *active {
+            AppId::Menu => {
+                if event == InputEvent::Primary {
+                    ctx.shared.menu_app.lock(|menu| *active = menu.selected_app());
+                } else {
+                    ctx.shared.menu_app.lock(|menu| menu.on_input(event));
+                }
+            }
+            AppId::Countdown => ctx.shared.countdown_app.lock(|app| app.on_input(event)),
+            AppId::Stopwatch => ctx.shared.stopwatch_app.lock(|app| app.on_input(event)),
+        });
+    }
+
+    // Repaint only the horizontal band of the progress bar that changed since
+    // the last tick, instead of redrawing the whole 240x240 fill.
+    fn update_progress_bar<D>(
+        display: &mut D,
+        last_height: &mut i32,
+        new_height: i32,
+        last_color: &mut Rgb565,
+        new_color: Rgb565,
+    ) where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        let old_height = *last_height;
+        if old_height < 0 {
+            // First draw since the bar view became active: paint it in full
+            Rectangle::new(
+                Point::new(0, SCREEN_HEIGHT - new_height),
+                Size::new(SCREEN_WIDTH as u32, new_height as u32),
+            )
+            .into_styled(PrimitiveStyle::with_fill(new_color))
+            .draw(display)
+            .ok();
+        } else {
+            let old_top = SCREEN_HEIGHT - old_height;
+            let new_top = SCREEN_HEIGHT - new_height;
+            if new_top < old_top {
+                // Bar grew: fill the newly covered band
+                Rectangle::new(
+                    Point::new(0, new_top),
+                    Size::new(SCREEN_WIDTH as u32, (old_top - new_top) as u32),
+                )
+                .into_styled(PrimitiveStyle::with_fill(new_color))
+                .draw(display)
+                .ok();
+            } else if new_top > old_top {
+                // Bar shrank: clear the newly exposed band
+                Rectangle::new(
+                    Point::new(0, old_top),
+                    Size::new(SCREEN_WIDTH as u32, (new_top - old_top) as u32),
+                )
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
+                .draw(display)
+                .ok();
+            }
+            if new_color != *last_color {
+                // The color changed (e.g. entering the final second):
+                // repaint the whole current bar in the new color
+                Rectangle::new(
+                    Point::new(0, new_top),
+                    Size::new(SCREEN_WIDTH as u32, new_height as u32),
+                )
+                .into_styled(PrimitiveStyle::with_fill(new_color))
+                .draw(display)
+                .ok();
+            }
         }
+        *last_height = new_height;
+        *last_color = new_color;
+    }
+
+    // Redraw the text label only when its contents or position changed,
+    // clearing just the old label's bounding box rather than the full screen.
+    // The label may straddle the progress bar's current top edge (`bar_top`),
+    // so the box is split there: the part below is cleared to `bar_color`
+    // (the bar's fill) and the part above to black, instead of assuming the
+    // whole box sits on one background.
+    fn update_text<D>(
+        display: &mut D,
+        last_text: &mut String<45>,
+        last_pos: &mut Point,
+        new_text: &str,
+        new_pos: Point,
+        style: MonoTextStyle<Rgb565>,
+        bar_top: i32,
+        bar_color: Rgb565,
+    ) where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        if new_text == last_text.as_str() && new_pos == *last_pos {
+            return;
+        }
+        if !last_text.is_empty() {
+            let old_box = Text::new(last_text.as_str(), *last_pos, style).bounding_box();
+            let top = old_box.top_left.y;
+            let bottom = top + old_box.size.height as i32;
+            let split = bar_top.clamp(top, bottom);
+
+            if split > top {
+                Rectangle::new(old_box.top_left, Size::new(old_box.size.width, (split - top) as u32))
+                    .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
+                    .draw(display)
+                    .ok();
+            }
+            if split < bottom {
+                Rectangle::new(
+                    Point::new(old_box.top_left.x, split),
+                    Size::new(old_box.size.width, (bottom - split) as u32),
+                )
+                .into_styled(PrimitiveStyle::with_fill(bar_color))
+                .draw(display)
+                .ok();
+            }
+        }
+        Text::new(new_text, new_pos, style).draw(display).ok();
+        last_text.clear();
+        last_text.push_str(new_text).ok();
+        *last_pos = new_pos;
+    }
+
+    // Draw a watch-style analog dial with tick marks and a sweep hand for the
+    // remaining time. `core` has no `sin`/`cos` in `no_std`, so this borrows
+    // them from `libm`.
+    fn draw_clock_face<D>(display: &mut D, time_left: i32, hand_color: Rgb565)
+    where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        Circle::with_center(Point::new(CENTER, CENTER), (DIAL_RADIUS * 2) as u32)
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 2))
+            .draw(display)
+            .ok();
+
+        // Tick marks every MAX_TIME/12 seconds around the dial
+        for i in 0..12 {
+            let theta = 2.0 * PI * (i as f32 / 12.0) - PI / 2.0;
+            let outer = Point::new(
+                CENTER + (DIAL_RADIUS as f32 * cosf(theta)) as i32,
+                CENTER + (DIAL_RADIUS as f32 * sinf(theta)) as i32,
+            );
+            let inner = Point::new(
+                CENTER + ((DIAL_RADIUS - 10) as f32 * cosf(theta)) as i32,
+                CENTER + ((DIAL_RADIUS - 10) as f32 * sinf(theta)) as i32,
+            );
+            Line::new(inner, outer)
+                .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 2))
+                .draw(display)
+                .ok();
+        }
+
+        // Sweep hand pointing at the remaining time
+        let theta = 2.0 * PI * (1.0 - time_left as f32 / MAX_TIME as f32) - PI / 2.0;
+        let hand_end = Point::new(
+            CENTER + (DIAL_RADIUS as f32 * cosf(theta)) as i32,
+            CENTER + (DIAL_RADIUS as f32 * sinf(theta)) as i32,
+        );
+        Line::new(Point::new(CENTER, CENTER), hand_end)
+            .into_styled(PrimitiveStyle::with_stroke(hand_color, 4))
+            .draw(display)
+            .ok();
     }
 
-}
\ No newline at end of file
+}